@@ -1,4 +1,10 @@
 mod converter;
+mod logging;
+
+// Headless conversions (tests, scripting) without a window
+pub use converter::{
+    run_conversion, ConvertErrorPayload, ConvertProgressEvent, ConvertRequest, ConvertResponse, ConvertResult,
+};
 
 use tauri::Manager;
 
@@ -27,21 +33,26 @@ pub fn run() {
                 let _ = win.set_focus();
             }
 
-            if cfg!(debug_assertions) {
-                app.handle().plugin(
-                    tauri_plugin_log::Builder::default()
-                        .level(log::LevelFilter::Info)
-                        .build(),
-                )?;
+            if let Some(plugin) = logging::plugin() {
+                app.handle().plugin(plugin)?;
             }
+
+            std::thread::spawn(converter::cleanup_stale_temp_dirs);
             Ok(())
         })
         .invoke_handler(tauri::generate_handler![
             converter::scan_frame_files,
             converter::convert_sequence_frames,
+            converter::estimate_conversion,
+            converter::check_capabilities,
+            converter::supported_input_extensions,
+            converter::benchmark_encoders,
+            converter::generate_preview,
             converter::pause_conversion,
             converter::resume_conversion,
-            converter::cancel_conversion
+            converter::cancel_conversion,
+            converter::cancel_all_conversions,
+            converter::reset_conversion_state
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
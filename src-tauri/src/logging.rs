@@ -0,0 +1,45 @@
+use tauri::plugin::TauriPlugin;
+use tauri::Runtime;
+use tauri_plugin_log::{RotationStrategy, Target, TargetKind};
+
+// Set to 1/true to keep a log file in release builds (debug builds always log)
+const LOG_FILE_ENV: &str = "FRAME_CONVERTER_LOG_FILE";
+// error | warn | info | debug | trace; defaults to info
+const LOG_LEVEL_ENV: &str = "FRAME_CONVERTER_LOG_LEVEL";
+const MAX_LOG_FILE_BYTES: u128 = 5 * 1024 * 1024;
+// Rotated files kept next to the active one, so the log dir stays under ~20 MB
+const ROTATED_LOG_FILES: usize = 3;
+
+fn env_flag(name: &str) -> bool {
+    std::env::var(name)
+        .map(|v| matches!(v.trim().to_ascii_lowercase().as_str(), "1" | "true" | "yes" | "on"))
+        .unwrap_or(false)
+}
+
+fn log_level() -> log::LevelFilter {
+    std::env::var(LOG_LEVEL_ENV)
+        .ok()
+        .and_then(|v| v.trim().parse().ok())
+        .unwrap_or(log::LevelFilter::Info)
+}
+
+// Logging sinks for run(): a size-capped, rotating file in the app's log dir (which also
+// captures the FFmpeg stderr the converter logs on failure), plus stdout in debug builds.
+// Release builds only log when LOG_FILE_ENV is set.
+pub fn plugin<R: Runtime>() -> Option<TauriPlugin<R>> {
+    let debug = cfg!(debug_assertions);
+    if !debug && !env_flag(LOG_FILE_ENV) {
+        return None;
+    }
+
+    let mut builder = tauri_plugin_log::Builder::default()
+        .level(log_level())
+        .clear_targets()
+        .target(Target::new(TargetKind::LogDir { file_name: None }))
+        .max_file_size(MAX_LOG_FILE_BYTES)
+        .rotation_strategy(RotationStrategy::KeepSome(ROTATED_LOG_FILES));
+    if debug {
+        builder = builder.target(Target::new(TargetKind::Stdout));
+    }
+    Some(builder.build())
+}
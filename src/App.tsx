@@ -35,13 +35,27 @@ type ConvertProgressEvent = {
   file?: string | null
 }
 
+type ConvertResponse = {
+  jobId: string
+  results: ConvertResult[]
+  stoppedEarly: boolean
+}
+
 type ConvertResult = {
   format: string
   path: string
   success: boolean
   error?: string | null
+  errorKind?: 'cancelled' | 'ffmpegMissing' | 'unsupportedFormat' | 'invalidInput' | 'decodeError' | 'encodeError' | 'ioError' | 'apiError' | 'quotaExceeded' | 'other' | null
   originalSize?: number | null
   compressedSize?: number | null
+  savingsBytes?: number | null
+  savingsPercent?: number | null
+  note?: string | null
+  warning?: string | null
+  qualityMetrics?: { ssim: number; psnr: number; framesCompared: number } | null
+  tinypngCompressionCount?: number | null
+  thumbnailPath?: string | null
 }
 
 function getBaseName(path: string): string {
@@ -75,6 +89,7 @@ export default function App() {
 
   const containerRef = useRef<HTMLDivElement | null>(null)
   const overlayRef = useRef<HTMLDivElement | null>(null)
+  const jobIdRef = useRef<string | null>(null)
   const dialogInFlightRef = useRef<{ input: boolean; inputFolder: boolean; outputDir: boolean }>({
     input: false,
     inputFolder: false,
@@ -141,11 +156,16 @@ export default function App() {
         // Ignore focus errors
       }
 
+      // The backend knows which formats it can decode (HEIC depends on the build and FFmpeg)
+      const extensions = await invoke<string[]>('supported_input_extensions').catch(() => [
+        'png', 'jpg', 'jpeg', 'webp', 'gif', 'apng',
+      ])
+
       // Open file dialog (supports multiple files)
       const picked = await open({
         directory: false,
         multiple: true,
-        filters: [{ name: 'Images', extensions: ['png', 'jpg', 'jpeg', 'webp', 'gif', 'apng'] }],
+        filters: [{ name: 'Images', extensions }],
       })
       if (reqId !== dialogReqIdRef.current.input) return
 
@@ -231,16 +251,16 @@ export default function App() {
 
   async function togglePause() {
     if (isPaused) {
-      await invoke('resume_conversion')
+      await invoke('resume_conversion', { jobId: jobIdRef.current })
       setIsPaused(false)
     } else {
-      await invoke('pause_conversion')
+      await invoke('pause_conversion', { jobId: jobIdRef.current })
       setIsPaused(true)
     }
   }
 
   async function cancelConvert() {
-    await invoke('cancel_conversion')
+    await invoke('cancel_conversion', { jobId: jobIdRef.current })
     setIsConverting(false)
     setIsPaused(false)
     setProgress(null)
@@ -254,10 +274,13 @@ export default function App() {
     setIsPaused(false)
     setProgress(null)
     setResults([])
+    const jobId = crypto.randomUUID()
+    jobIdRef.current = jobId
 
     try {
-      const convertResults = await invoke<ConvertResult[]>('convert_sequence_frames', {
+      const response = await invoke<ConvertResponse>('convert_sequence_frames', {
         request: {
+          jobId,
           inputMode: isFolder ? 'folder' : 'file',
           inputPath,
           inputPaths: !isFolder ? inputPaths : null,
@@ -270,7 +293,7 @@ export default function App() {
           compressionQuality: Number(compressionQuality),
         }
       })
-      setResults(convertResults)
+      setResults(response.results)
     } catch (error) {
       console.error('Convert error:', error)
     } finally {
@@ -743,12 +766,24 @@ export default function App() {
                       )}
                       <span className="font-semibold">{result.format.toUpperCase()}</span>
                       <span className="text-white/20 truncate">{result.path}</span>
+                      {result.note && <span className="text-xs text-white/12">{result.note}</span>}
+                      {result.warning && <span className="text-xs text-white/12">{result.warning}</span>}
+                      {result.tinypngCompressionCount != null && (
+                        <span className="text-xs text-white/12">
+                          TinyPNG: {result.tinypngCompressionCount} compressions this month
+                        </span>
+                      )}
+                      {result.qualityMetrics && (
+                        <span className="text-xs text-white/12">
+                          SSIM {result.qualityMetrics.ssim.toFixed(4)} · PSNR {result.qualityMetrics.psnr.toFixed(2)} dB
+                        </span>
+                      )}
                     </div>
                     {result.success && result.originalSize && (
                       <div className="text-xs text-white/12">
-                        {result.compressedSize ? (
+                        {result.savingsPercent != null ? (
                           <>
-                            {((result.compressedSize / result.originalSize) * 100).toFixed(1)}% of original
+                            {(100 - result.savingsPercent).toFixed(1)}% of original
                           </>
                         ) : (
                           <>{(result.originalSize / 1024).toFixed(1)} KB</>